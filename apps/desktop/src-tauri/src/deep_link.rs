@@ -0,0 +1,134 @@
+//! Routes "Open with Spacedrive" file launches and `spacedrive://` deep links
+//! to the `main` window as an `app://open-items` event.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager, Runtime};
+use tracing::{debug, warn};
+
+const DEEP_LINK_SCHEME: &str = "spacedrive://";
+const OPEN_ITEMS_EVENT: &str = "app://open-items";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Type)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OpenItem {
+	Path { path: PathBuf },
+	Url { url: String },
+}
+
+/// Splits a launch's raw argv/URL list into file paths and `spacedrive://`
+/// deep links, skipping anything that's neither (e.g. a flag an OS launcher
+/// tacked on). Any existing path is kept, so callers strip the exe path
+/// (`argv[0]`) themselves before calling this.
+///
+/// macOS delivers Finder "Open With" launches as `file://` URLs rather than
+/// plain paths (that's why `RunEvent::Opened` hands us `Url`s, not
+/// `PathBuf`s), so those are converted to a filesystem path before the
+/// existence check below - otherwise they'd never match.
+fn parse_open_items(items: &[String]) -> Vec<OpenItem> {
+	items
+		.iter()
+		.filter_map(|item| {
+			if let Some(rest) = item.strip_prefix(DEEP_LINK_SCHEME) {
+				Some(OpenItem::Url {
+					url: format!("{DEEP_LINK_SCHEME}{rest}"),
+				})
+			} else {
+				let path = url::Url::parse(item)
+					.ok()
+					.and_then(|url| url.to_file_path().ok())
+					.unwrap_or_else(|| PathBuf::from(item));
+
+				path.exists().then_some(OpenItem::Path { path })
+			}
+		})
+		.collect()
+}
+
+/// Parses `items` and, if any were recognised as a file path or deep link,
+/// emits them to the `main` window as `app://open-items` and brings it to the
+/// front.
+pub(crate) fn emit_open_items<R: Runtime>(app_handle: &AppHandle<R>, items: Vec<String>) {
+	let items = parse_open_items(&items);
+
+	if items.is_empty() {
+		return;
+	}
+
+	debug!("Forwarding {} opened item(s) to the main window", items.len());
+
+	let Some(window) = app_handle.get_window("main") else {
+		warn!("No main window to forward opened items to");
+		return;
+	};
+
+	if let Err(err) = window.emit(OPEN_ITEMS_EVENT, items) {
+		warn!("Failed to emit {OPEN_ITEMS_EVENT}: {err}");
+	}
+
+	let _ = window.set_focus();
+	let _ = window.show();
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs;
+
+	use super::*;
+
+	/// A file that exists for the duration of the test, so `parse_open_items`'s
+	/// existence check has something real to find.
+	struct TempFile(PathBuf);
+
+	impl TempFile {
+		fn new(name: &str) -> Self {
+			let path = std::env::temp_dir().join(format!("sd-deep-link-test-{}-{name}", std::process::id()));
+			fs::write(&path, b"").unwrap();
+			Self(path)
+		}
+	}
+
+	impl Drop for TempFile {
+		fn drop(&mut self) {
+			let _ = fs::remove_file(&self.0);
+		}
+	}
+
+	#[test]
+	fn deep_link_is_recognised() {
+		let items = parse_open_items(&["spacedrive://location/1".to_string()]);
+		assert_eq!(
+			items,
+			vec![OpenItem::Url {
+				url: "spacedrive://location/1".to_string()
+			}]
+		);
+	}
+
+	#[test]
+	fn existing_plain_path_is_recognised() {
+		let file = TempFile::new("plain");
+
+		let items = parse_open_items(&[file.0.to_string_lossy().to_string()]);
+
+		assert_eq!(items, vec![OpenItem::Path { path: file.0.clone() }]);
+	}
+
+	#[test]
+	fn existing_file_url_is_converted_to_a_path() {
+		let file = TempFile::new("url");
+		let url = url::Url::from_file_path(&file.0).unwrap();
+
+		let items = parse_open_items(&[url.to_string()]);
+
+		assert_eq!(items, vec![OpenItem::Path { path: file.0.clone() }]);
+	}
+
+	#[test]
+	fn nonexistent_path_is_dropped() {
+		let items = parse_open_items(&["/nonexistent/path/should-not-exist".to_string()]);
+		assert!(items.is_empty());
+	}
+}
@@ -17,11 +17,18 @@ use tracing::{debug, error};
 #[cfg(target_os = "linux")]
 mod app_linux;
 
+#[cfg(any(target_os = "android", target_os = "ios"))]
+mod mobile;
+
 mod theme;
 
+mod deep_link;
 mod file;
 mod menu;
 
+#[cfg(feature = "updater")]
+mod updater;
+
 #[tauri::command(async)]
 #[specta::specta]
 async fn app_ready(app_handle: AppHandle) {
@@ -32,20 +39,20 @@ async fn app_ready(app_handle: AppHandle) {
 
 #[tauri::command(async)]
 #[specta::specta]
-async fn reset_spacedrive(app_handle: AppHandle) {
-	let data_dir = path::data_dir()
-		.unwrap_or_else(|| PathBuf::from("./"))
-		.join("spacedrive");
-
-	#[cfg(debug_assertions)]
-	let data_dir = data_dir.join("dev");
-
-	fs::remove_dir_all(data_dir).unwrap();
+async fn reset_spacedrive(
+	app_handle: AppHandle,
+	node: tauri::State<'_, Arc<Node>>,
+) -> Result<(), ()> {
+	// Reuse the dir the node was actually opened with, same as `open_logs_dir`
+	// below, rather than recomputing it - mobile's data dir isn't `path::data_dir()`.
+	fs::remove_dir_all(&node.data_dir).unwrap();
 
 	// TODO: Restarting the app doesn't work in dev (cause Tauri's devserver shutdown) and in prod makes the app go unresponsive until you click in/out on macOS
 	// app_handle.restart();
 
 	app_handle.exit(0);
+
+	Ok(())
 }
 
 #[tauri::command(async)]
@@ -74,14 +81,58 @@ pub fn tauri_error_plugin<R: Runtime>(err: NodeError) -> TauriPlugin<R> {
 }
 
 macro_rules! tauri_handlers {
-	($($name:path),+) => {{
+	($($(#[$attr:meta])* $name:path),+ $(,)?) => {{
 		#[cfg(debug_assertions)]
-		tauri_specta::ts::export(specta::collect_types![$($name),+], "../src/commands.ts").unwrap();
+		tauri_specta::ts::export(specta::collect_types![$($(#[$attr])* $name),+], "../src/commands.ts").unwrap();
 
-		tauri::generate_handler![$($name),+]
+		tauri::generate_handler![$($(#[$attr])* $name),+]
 	}};
 }
 
+pub(crate) use tauri_handlers;
+
+/// Boots the core `Node`, wires up the `spacedrive://` custom URI endpoint and the
+/// rspc plugin, and hands back the (possibly absent, if startup failed) node
+/// alongside the builder with those pieces attached. Shared between the desktop
+/// `main` and the mobile `#[tauri::mobile_entry_point]` so both platforms boot the
+/// core the same way.
+pub(crate) async fn setup_app<R: Runtime>(
+	builder: tauri::Builder<R>,
+	data_dir: PathBuf,
+) -> (Option<Arc<Node>>, tauri::Builder<R>) {
+	match Node::new(data_dir).await {
+		Ok((node, router)) => {
+			let builder = builder
+				.register_uri_scheme_protocol(
+					"spacedrive",
+					create_custom_uri_endpoint(node.clone()).tauri_uri_scheme("spacedrive"),
+				)
+				.plugin(rspc::integrations::tauri::plugin(router, {
+					let node = node.clone();
+					move |_| node.clone()
+				}))
+				.manage(node.clone());
+
+			(Some(node), builder)
+		}
+		Err(err) => {
+			tracing::error!("Error starting up the node: {err}");
+			(None, builder.plugin(tauri_error_plugin(err)))
+		}
+	}
+}
+
+/// Grants the `spacedrive://` scheme access to the Tauri IPC bridge from the
+/// `main` window. Needed on every platform the node runs on, desktop or mobile.
+pub(crate) fn configure_ipc_scope<R: Runtime>(app: &AppHandle<R>) {
+	app.ipc_scope().configure_remote_access(
+		RemoteDomainAccessScope::new("localhost")
+			.allow_on_scheme("spacedrive")
+			.add_window("main")
+			.enable_tauri_api(),
+	);
+}
+
 #[tokio::main]
 async fn main() -> tauri::Result<()> {
 	#[cfg(target_os = "linux")]
@@ -101,33 +152,23 @@ async fn main() -> tauri::Result<()> {
 	// Return value must be assigned to variable for flushing remaining logs on main exit throught Drop
 	let _guard = Node::init_logger(&data_dir);
 
-	let result = Node::new(data_dir).await;
+	// Must be registered before any other plugin: forwards a second launch's
+	// argv (e.g. from "Open With" or a `spacedrive://` link) to this instance
+	// instead of letting it start a second node against the same data dir.
+	let app = tauri::Builder::default().plugin(tauri_plugin_single_instance::init(
+		|app_handle, argv, _cwd| {
+			deep_link::emit_open_items(app_handle, argv.into_iter().skip(1).collect());
+		},
+	));
 
-	let app = tauri::Builder::default();
-
-	let (node, app) = match result {
-		Ok((node, router)) => {
-			// This is a super cringe workaround for: https://github.com/tauri-apps/tauri/issues/3725 & https://bugs.webkit.org/show_bug.cgi?id=146351#c5
-			#[cfg(target_os = "linux")]
-			let app = app_linux::setup(app, rx, create_custom_uri_endpoint(node.clone()).axum()).await;
-
-			let app = app
-				.register_uri_scheme_protocol(
-					"spacedrive",
-					create_custom_uri_endpoint(node.clone()).tauri_uri_scheme("spacedrive"),
-				)
-				.plugin(rspc::integrations::tauri::plugin(router, {
-					let node = node.clone();
-					move |_| node.clone()
-				}))
-				.manage(node.clone());
+	let (node, app) = setup_app(app, data_dir).await;
 
-			(Some(node), app)
-		}
-		Err(err) => {
-			tracing::error!("Error starting up the node: {err}");
-			(None, app.plugin(tauri_error_plugin(err)))
-		}
+	// This is a super cringe workaround for: https://github.com/tauri-apps/tauri/issues/3725 & https://bugs.webkit.org/show_bug.cgi?id=146351#c5
+	#[cfg(target_os = "linux")]
+	let app = if let Some(node) = &node {
+		app_linux::setup(app, rx, create_custom_uri_endpoint(node.clone()).axum()).await
+	} else {
+		app
 	};
 
 	// macOS expected behavior is for the app to not exit when the main window is closed.
@@ -144,8 +185,12 @@ async fn main() -> tauri::Result<()> {
 
 	let app = app
 		.setup(|app| {
+			// Updates are driven entirely by the frontend via the `check_for_update`/
+			// `set_update_consent`/`install_update` commands below, so there's no
+			// background updater to configure here - just the consent flag
+			// `install_update` gates on.
 			#[cfg(feature = "updater")]
-			tauri::updater::builder(app.handle()).should_install(|_current, _latest| true);
+			app.manage(updater::UpdateConsent::default());
 
 			let app = app.handle();
 
@@ -180,12 +225,13 @@ async fn main() -> tauri::Result<()> {
 			});
 
 			// Configure IPC for custom protocol
-			app.ipc_scope().configure_remote_access(
-				RemoteDomainAccessScope::new("localhost")
-					.allow_on_scheme("spacedrive")
-					.add_window("main")
-					.enable_tauri_api(),
-			);
+			configure_ipc_scope(app);
+
+			// Forward files/deep-links this instance was launched with (e.g. via
+			// "Open With" or a `spacedrive://` link) - later launches are handled by
+			// the single-instance plugin above, and macOS open-file/open-url events
+			// are handled via `RunEvent::Opened` below.
+			deep_link::emit_open_items(app, std::env::args().skip(1).collect());
 
 			Ok(())
 		})
@@ -199,11 +245,27 @@ async fn main() -> tauri::Result<()> {
 			file::get_file_path_open_with_apps,
 			file::open_file_path_with,
 			file::reveal_items,
-			theme::lock_app_theme
+			theme::lock_app_theme,
+			#[cfg(feature = "updater")]
+			updater::check_for_update,
+			#[cfg(feature = "updater")]
+			updater::set_update_consent,
+			#[cfg(feature = "updater")]
+			updater::install_update
 		])
 		.build(tauri::generate_context!())?;
 
 	app.run(move |app_handler, event| {
+		// Unified macOS open-file/open-url callback (Finder "Open With" and
+		// `spacedrive://` links both surface here).
+		#[cfg(target_os = "macos")]
+		if let RunEvent::Opened { urls } = &event {
+			deep_link::emit_open_items(
+				app_handler,
+				urls.iter().map(ToString::to_string).collect(),
+			);
+		}
+
 		if let RunEvent::ExitRequested { .. } = event {
 			debug!("Closing all open windows...");
 			app_handler
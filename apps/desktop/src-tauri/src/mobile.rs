@@ -0,0 +1,95 @@
+//! Mobile (iOS/Android) entry point. Shares the node bootstrap and IPC wiring
+//! with desktop via [`crate::setup_app`], but resolves its own sandboxed data
+//! dir and skips the desktop-only window chrome.
+
+use std::path::PathBuf;
+
+use sd_core::Node;
+
+use crate::{configure_ipc_scope, file, setup_app, tauri_handlers, theme};
+
+/// Mobile platforms don't expose a writable OS-wide `data_dir` the way desktop
+/// does - each app is confined to its own sandboxed container.
+fn mobile_data_dir() -> PathBuf {
+	#[cfg(target_os = "ios")]
+	let dir = PathBuf::from(std::env::var("HOME").expect("HOME should be set inside the app sandbox"))
+		.join("Documents")
+		.join("spacedrive");
+
+	#[cfg(target_os = "android")]
+	let dir = android_files_dir().join("spacedrive");
+
+	#[cfg(debug_assertions)]
+	let dir = dir.join("dev");
+
+	dir
+}
+
+/// Resolves the running Activity's app-private storage directory
+/// (`Context#getFilesDir()`) over JNI. Android has no OS-wide data dir the way
+/// `tauri::api::path::data_dir()` assumes on desktop - that call falls through
+/// to a platform default that isn't actually backed by our app's sandbox.
+#[cfg(target_os = "android")]
+fn android_files_dir() -> PathBuf {
+	let ctx = ndk_context::android_context();
+
+	// SAFETY: `vm()`/`context()` are populated by Tauri's Android runtime
+	// before `#[tauri::mobile_entry_point]` ever runs.
+	let vm = unsafe { jni::JavaVM::from_raw(ctx.vm().cast()) }.expect("valid JavaVM pointer");
+	let mut env = vm
+		.attach_current_thread()
+		.expect("failed to attach JNI thread");
+	let activity = unsafe { jni::objects::JObject::from_raw(ctx.context().cast()) };
+
+	let files_dir = env
+		.call_method(&activity, "getFilesDir", "()Ljava/io/File;", &[])
+		.and_then(jni::objects::JValueOwned::l)
+		.expect("Activity#getFilesDir() should not throw");
+
+	let path = env
+		.call_method(&files_dir, "getAbsolutePath", "()Ljava/lang/String;", &[])
+		.and_then(jni::objects::JValueOwned::l)
+		.expect("File#getAbsolutePath() should not throw");
+
+	let path = jni::objects::JString::from(path);
+
+	env.get_string(&path)
+		.map(|s| PathBuf::from(String::from(s)))
+		.expect("File#getAbsolutePath() should return valid UTF-8")
+}
+
+#[tauri::mobile_entry_point]
+fn main() {
+	let data_dir = mobile_data_dir();
+
+	// Return value must be assigned to a variable for flushing remaining logs on exit through Drop
+	let _guard = Node::init_logger(&data_dir);
+
+	tauri::async_runtime::block_on(async move {
+		let (_node, app) = setup_app(tauri::Builder::default(), data_dir).await;
+
+		let app = app
+			.setup(|app| {
+				let app = app.handle();
+
+				// Configure IPC for custom protocol
+				configure_ipc_scope(app);
+
+				Ok(())
+			})
+			.invoke_handler(tauri_handlers![
+				crate::app_ready,
+				crate::reset_spacedrive,
+				crate::open_logs_dir,
+				file::open_file_paths,
+				file::get_file_path_open_with_apps,
+				file::open_file_path_with,
+				file::reveal_items,
+				theme::lock_app_theme
+			])
+			.build(tauri::generate_context!())
+			.expect("error while building the mobile tauri application");
+
+		app.run(|_app_handle, _event| {});
+	});
+}
@@ -0,0 +1,167 @@
+//! In-app update commands exposed to the frontend: `check_for_update`,
+//! `set_update_consent`, `install_update`. Linux AppImage builds report
+//! updates as managed externally rather than attempting to self-update.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use specta::Type;
+use tauri::{AppHandle, Manager, Runtime};
+use tracing::error;
+
+/// Tracks which update version the user has explicitly agreed, via
+/// [`set_update_consent`], to install. `install_update` re-checks for an
+/// update itself and only proceeds if that check still reports this same
+/// version, so a newer release appearing between the user's confirmation and
+/// the install can't ride in on a stale "yes".
+#[derive(Default)]
+pub(crate) struct UpdateConsent(Mutex<Option<String>>);
+
+impl UpdateConsent {
+	pub(crate) fn get(&self) -> Option<String> {
+		self.0.lock().expect("not poisoned").clone()
+	}
+
+	pub(crate) fn set(&self, version: Option<String>) {
+		*self.0.lock().expect("not poisoned") = version;
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInfo {
+	pub version: String,
+	pub release_notes: Option<String>,
+	pub date: Option<String>,
+	pub download_size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum UpdateStatus {
+	/// An update was found and `info` describes it.
+	Available { info: UpdateInfo },
+	/// The app is already on the latest version.
+	UpToDate,
+	/// This platform doesn't support in-app updates (currently just Linux).
+	ManagedExternally,
+}
+
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadProgress {
+	pub bytes_downloaded: u64,
+	pub total_bytes: Option<u64>,
+}
+
+#[tauri::command(async)]
+#[specta::specta]
+pub async fn check_for_update<R: Runtime>(
+	app_handle: AppHandle<R>,
+) -> Result<UpdateStatus, String> {
+	#[cfg(target_os = "linux")]
+	{
+		let _ = app_handle;
+		Ok(UpdateStatus::ManagedExternally)
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	{
+		let update = tauri::updater::builder(app_handle)
+			.check()
+			.await
+			.map_err(|err| {
+				error!("Failed to check for update: {err}");
+				err.to_string()
+			})?;
+
+		Ok(if update.is_update_available() {
+			UpdateStatus::Available {
+				info: UpdateInfo {
+					version: update.latest_version().to_string(),
+					release_notes: update.body().map(str::to_string),
+					date: update.date().map(|date| date.to_string()),
+					download_size: update.content_length(),
+				},
+			}
+		} else {
+			UpdateStatus::UpToDate
+		})
+	}
+}
+
+/// Records that the user has confirmed, from a frontend-driven confirmation
+/// step, that they want `version` - the update `check_for_update` reported -
+/// installed. `install_update` checks the consented version still matches
+/// what it finds rather than assuming consent from the mere fact it was
+/// called, since that call can itself be wired to happen automatically.
+#[tauri::command(async)]
+#[specta::specta]
+pub async fn set_update_consent<R: Runtime>(app_handle: AppHandle<R>, version: String) {
+	app_handle.state::<UpdateConsent>().set(Some(version));
+}
+
+/// Downloads and installs the update that's currently available, emitting
+/// `update://download-progress` events as the bundle streams in. Refuses to
+/// run unless [`set_update_consent`] was called for this exact version.
+#[tauri::command(async)]
+#[specta::specta]
+pub async fn install_update<R: Runtime>(app_handle: AppHandle<R>) -> Result<(), String> {
+	#[cfg(target_os = "linux")]
+	{
+		let _ = app_handle;
+		Err("Updates on Linux are managed externally (e.g. via your package manager)".to_string())
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	{
+		let Some(consented_version) = app_handle.state::<UpdateConsent>().get() else {
+			return Err("Install was not confirmed by the user".to_string());
+		};
+
+		let update = tauri::updater::builder(app_handle.clone())
+			.check()
+			.await
+			.map_err(|err| err.to_string())?;
+
+		if !update.is_update_available() {
+			app_handle.state::<UpdateConsent>().set(None);
+			return Err("No update is available to install".to_string());
+		}
+
+		if update.latest_version() != consented_version {
+			// A different update showed up since the user confirmed - don't let a
+			// stale consent authorize installing a version they never saw.
+			app_handle.state::<UpdateConsent>().set(None);
+			return Err(
+				"A different update is now available; please confirm again before installing"
+					.to_string(),
+			);
+		}
+
+		let result = update
+			.download_and_install({
+				let app_handle = app_handle.clone();
+				move |bytes_downloaded, total_bytes| {
+					let _ = app_handle.emit_all(
+						"update://download-progress",
+						DownloadProgress {
+							bytes_downloaded,
+							total_bytes,
+						},
+					);
+				}
+			})
+			.await
+			.map_err(|err| {
+				error!("Failed to install update: {err}");
+				err.to_string()
+			});
+
+		// Consent is single-use either way: a failed install shouldn't silently
+		// retry, and a successful one has nothing left to consent to.
+		app_handle.state::<UpdateConsent>().set(None);
+
+		result
+	}
+}
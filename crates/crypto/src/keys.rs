@@ -0,0 +1,57 @@
+//! In-process master/verification key handling for a node's library, backed
+//! by a pluggable [`KeyStore`] so a key doesn't have to be re-derived from a
+//! passphrase on every launch.
+
+use crate::{
+	keystore::{KeyStore, KeyStoreError},
+	Protected,
+};
+
+const MASTER_KEY_LABEL: &str = "master_key";
+
+/// Holds a node's keys for the lifetime of the process, and knows where they
+/// should be persisted between launches.
+pub struct KeyManager {
+	store: Box<dyn KeyStore>,
+	master_key: Option<Protected<Vec<u8>>>,
+}
+
+impl KeyManager {
+	#[must_use]
+	pub fn new(store: Box<dyn KeyStore>) -> Self {
+		Self {
+			store,
+			master_key: None,
+		}
+	}
+
+	/// Returns the master key without prompting for a passphrase, either from
+	/// memory or, failing that, from the keystore. Callers should fall back to
+	/// a passphrase-derived unlock on `Err(KeyStoreError::NotFound)`.
+	pub fn unlock_from_keystore(&mut self) -> Result<Protected<Vec<u8>>, KeyStoreError> {
+		if let Some(key) = &self.master_key {
+			return Ok(key.clone());
+		}
+
+		let key = self.store.retrieve(MASTER_KEY_LABEL)?;
+		self.master_key = Some(key.clone());
+
+		Ok(key)
+	}
+
+	/// Called once a passphrase-derived unlock succeeds, so the next launch
+	/// can go straight through [`unlock_from_keystore`] instead.
+	pub fn remember(&mut self, master_key: Protected<Vec<u8>>) -> Result<(), KeyStoreError> {
+		self.store.store(MASTER_KEY_LABEL, master_key.clone())?;
+		self.master_key = Some(master_key);
+
+		Ok(())
+	}
+
+	/// Drops the key from memory and the keystore, requiring the passphrase
+	/// again on the next unlock.
+	pub fn forget(&mut self) -> Result<(), KeyStoreError> {
+		self.master_key = None;
+		self.store.delete(MASTER_KEY_LABEL)
+	}
+}
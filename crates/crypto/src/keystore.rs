@@ -0,0 +1,121 @@
+//! Pluggable secure-storage backends for a node's master/verification key.
+//! [`InMemoryKeyStore`] is the default; the `keyring` feature adds
+//! [`OsKeyStore`], backed by the platform credential store.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::Protected;
+
+/// Raised when a [`KeyStore`] backend can't complete an operation.
+#[derive(Debug, thiserror::Error)]
+pub enum KeyStoreError {
+	#[error("no key stored under this label")]
+	NotFound,
+	#[error("keystore backend error: {0}")]
+	Backend(String),
+}
+
+/// A place a node's master/verification key can be persisted to, independent
+/// of the in-process key management in [`crate::keys`].
+pub trait KeyStore: Send + Sync {
+	/// Persists `key` under `label`, overwriting any existing value.
+	fn store(&self, label: &str, key: Protected<Vec<u8>>) -> Result<(), KeyStoreError>;
+
+	/// Retrieves the key previously stored under `label`.
+	fn retrieve(&self, label: &str) -> Result<Protected<Vec<u8>>, KeyStoreError>;
+
+	/// Removes the key stored under `label`, if any.
+	fn delete(&self, label: &str) -> Result<(), KeyStoreError>;
+}
+
+/// Default backend - keeps keys in a process-local map. Nothing is persisted
+/// to disk, so this offers no "skip the passphrase" benefit on its own, but it
+/// needs no OS integration and is always available.
+#[derive(Default)]
+pub struct InMemoryKeyStore(Mutex<HashMap<String, Protected<Vec<u8>>>>);
+
+impl KeyStore for InMemoryKeyStore {
+	fn store(&self, label: &str, key: Protected<Vec<u8>>) -> Result<(), KeyStoreError> {
+		self.0
+			.lock()
+			.map_err(|_| KeyStoreError::Backend("poisoned lock".to_string()))?
+			.insert(label.to_string(), key);
+
+		Ok(())
+	}
+
+	fn retrieve(&self, label: &str) -> Result<Protected<Vec<u8>>, KeyStoreError> {
+		self.0
+			.lock()
+			.map_err(|_| KeyStoreError::Backend("poisoned lock".to_string()))?
+			.get(label)
+			.cloned()
+			.ok_or(KeyStoreError::NotFound)
+	}
+
+	fn delete(&self, label: &str) -> Result<(), KeyStoreError> {
+		self.0
+			.lock()
+			.map_err(|_| KeyStoreError::Backend("poisoned lock".to_string()))?
+			.remove(label);
+
+		Ok(())
+	}
+}
+
+/// OS credential-store backend, scoped under a single `service` name (e.g.
+/// `"spacedrive"`) so entries from other apps in the same store aren't
+/// touched.
+#[cfg(feature = "keyring")]
+pub struct OsKeyStore {
+	service: String,
+}
+
+#[cfg(feature = "keyring")]
+impl OsKeyStore {
+	#[must_use]
+	pub fn new(service: impl Into<String>) -> Self {
+		Self {
+			service: service.into(),
+		}
+	}
+
+	fn entry(&self, label: &str) -> Result<keyring::Entry, KeyStoreError> {
+		keyring::Entry::new(&self.service, label)
+			.map_err(|err| KeyStoreError::Backend(err.to_string()))
+	}
+}
+
+#[cfg(feature = "keyring")]
+impl KeyStore for OsKeyStore {
+	fn store(&self, label: &str, key: Protected<Vec<u8>>) -> Result<(), KeyStoreError> {
+		// Wrapped immediately so the base64 copy of the secret is zeroized on
+		// drop rather than lingering in whatever buffer `encode` returned.
+		let encoded = Protected::new(data_encoding::BASE64.encode(key.expose()));
+
+		self.entry(label)?
+			.set_password(encoded.expose())
+			.map_err(|err| KeyStoreError::Backend(err.to_string()))
+	}
+
+	fn retrieve(&self, label: &str) -> Result<Protected<Vec<u8>>, KeyStoreError> {
+		let encoded = self.entry(label)?.get_password().map_err(|err| match err {
+			keyring::Error::NoEntry => KeyStoreError::NotFound,
+			err => KeyStoreError::Backend(err.to_string()),
+		})?;
+		let encoded = Protected::new(encoded);
+
+		data_encoding::BASE64
+			.decode(encoded.expose().as_bytes())
+			.map(Protected::new)
+			.map_err(|err| KeyStoreError::Backend(err.to_string()))
+	}
+
+	fn delete(&self, label: &str) -> Result<(), KeyStoreError> {
+		match self.entry(label)?.delete_password() {
+			Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+			Err(err) => Err(KeyStoreError::Backend(err.to_string())),
+		}
+	}
+}
@@ -16,6 +16,7 @@
 pub mod crypto;
 pub mod error;
 pub mod keys;
+pub mod keystore;
 pub mod primitives;
 pub mod protected;
 pub mod types;
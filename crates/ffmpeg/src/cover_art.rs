@@ -0,0 +1,59 @@
+//! Extracts cover art / attached-picture streams (ID3 `APIC`, MP4 `covr`, ...)
+//! straight from a container's `AV_DISPOSITION_ATTACHED_PIC` stream, instead
+//! of synthesizing a thumbnail by decoding a video frame.
+
+use std::slice;
+
+use ffmpeg_sys_next::{AVCodecID, AVFormatContext, AV_DISPOSITION_ATTACHED_PIC};
+
+/// A still image embedded in a media container, extracted as-is.
+#[derive(Debug, Clone)]
+pub struct EmbeddedImage {
+	pub data: Vec<u8>,
+	pub mime_type: String,
+}
+
+fn mime_type_for_codec(codec_id: AVCodecID) -> &'static str {
+	match codec_id {
+		ffmpeg_sys_next::AV_CODEC_ID_MJPEG => "image/jpeg",
+		ffmpeg_sys_next::AV_CODEC_ID_PNG => "image/png",
+		ffmpeg_sys_next::AV_CODEC_ID_BMP => "image/bmp",
+		ffmpeg_sys_next::AV_CODEC_ID_GIF => "image/gif",
+		_ => "application/octet-stream",
+	}
+}
+
+/// Walks `format_ctx`'s streams for one flagged `AV_DISPOSITION_ATTACHED_PIC`
+/// and returns its packet data. Returns the first match, since containers only
+/// ever carry a single embedded cover.
+///
+/// # Safety
+/// `format_ctx` must be a valid, opened `AVFormatContext` pointer, as returned
+/// by `avformat_open_input`.
+pub(crate) unsafe fn extract_embedded_artwork(
+	format_ctx: *mut AVFormatContext,
+) -> Option<EmbeddedImage> {
+	if format_ctx.is_null() {
+		return None;
+	}
+
+	let streams = slice::from_raw_parts((*format_ctx).streams, (*format_ctx).nb_streams as usize);
+
+	streams.iter().find_map(|&stream| {
+		let stream = stream.as_ref()?;
+
+		if stream.disposition & AV_DISPOSITION_ATTACHED_PIC == 0 {
+			return None;
+		}
+
+		let packet = &stream.attached_pic;
+		if packet.data.is_null() || packet.size <= 0 {
+			return None;
+		}
+
+		Some(EmbeddedImage {
+			data: slice::from_raw_parts(packet.data, packet.size as usize).to_vec(),
+			mime_type: mime_type_for_codec((*stream.codecpar).codec_id).to_string(),
+		})
+	})
+}
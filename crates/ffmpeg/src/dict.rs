@@ -1,4 +1,7 @@
-use crate::{error::Error, model::MediaMetadata, utils::check_error};
+use crate::{
+	cover_art::extract_embedded_artwork, error::Error, geolocation::parse_iso6709,
+	model::MediaMetadata, utils::check_error,
+};
 
 use std::{
 	ffi::{CStr, CString},
@@ -8,6 +11,7 @@ use std::{
 use chrono::DateTime;
 use ffmpeg_sys_next::{
 	av_dict_free, av_dict_get, av_dict_iterate, av_dict_set, AVDictionary, AVDictionaryEntry,
+	AVFormatContext,
 };
 
 #[derive(Debug)]
@@ -151,6 +155,13 @@ impl From<FFmpegDict> for MediaMetadata {
 					"filename" => media_metadata.filename = Some(value.clone()),
 					"genre" => media_metadata.genre = Some(value.clone()),
 					"language" => media_metadata.language = Some(value.clone()),
+					"location" | "com.apple.quicktime.location.ISO6709" => {
+						if let Some(location) = parse_iso6709(&value) {
+							media_metadata.location = Some(location);
+						} else {
+							media_metadata.custom.insert(key.clone(), value.clone());
+						}
+					}
 					"performer" => media_metadata.performer = Some(value.clone()),
 					"publisher" => media_metadata.publisher = Some(value.clone()),
 					"service_name" => media_metadata.service_name = Some(value.clone()),
@@ -176,3 +187,19 @@ impl From<FFmpegDict> for MediaMetadata {
 		media_metadata
 	}
 }
+
+/// Builds a [`MediaMetadata`] from `format_ctx` in a single pass: the textual
+/// tags via the usual [`FFmpegDict`] conversion above, plus any embedded cover
+/// art the container carries as an attached-picture stream.
+///
+/// # Safety
+/// `format_ctx` must be a valid, opened `AVFormatContext` pointer, as returned
+/// by `avformat_open_input`.
+pub(crate) unsafe fn probe_media_metadata(
+	format_ctx: *mut AVFormatContext,
+	av_dict: Option<&mut AVDictionary>,
+) -> MediaMetadata {
+	let mut media_metadata = MediaMetadata::from(FFmpegDict::new(av_dict));
+	media_metadata.embedded_artwork = extract_embedded_artwork(format_ctx);
+	media_metadata
+}
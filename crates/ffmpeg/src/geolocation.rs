@@ -0,0 +1,141 @@
+//! Parses ISO 6709 geographic point strings, as used by the `location` /
+//! `com.apple.quicktime.location.ISO6709` metadata tags phone cameras stamp
+//! onto videos.
+
+/// A geographic point parsed from an ISO 6709 string.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geolocation {
+	pub latitude: f64,
+	pub longitude: f64,
+	pub altitude: Option<f64>,
+}
+
+/// Parses a concatenated ISO 6709 string, e.g. `+40.7589-073.9851+010.273/`
+/// (latitude, then longitude, then an optional altitude in metres, terminated
+/// by `/`). There's no delimiter between fields other than their own sign, so
+/// we split on each `+`/`-` we see and parse the resulting tokens.
+///
+/// Latitude/longitude may each be in plain-degree (`+40.7589`), degree-minute
+/// (`+4045.6`, i.e. `DDMM.M`), or degree-minute-second (`+404530.5`) form -
+/// the field width before the decimal point is fixed per form, so
+/// [`parse_coordinate`] tells them apart by counting digits.
+///
+/// Returns `None` if fewer than two signed fields are found or either of the
+/// first two fail to parse as a coordinate, so the caller can fall back to
+/// keeping the raw tag value instead of storing something nonsensical.
+pub(crate) fn parse_iso6709(value: &str) -> Option<Geolocation> {
+	let value = value.trim().trim_end_matches('/');
+
+	let mut fields = Vec::with_capacity(3);
+	let mut start = None;
+
+	for (i, c) in value.char_indices() {
+		if c == '+' || c == '-' {
+			if let Some(start) = start {
+				fields.push(&value[start..i]);
+			}
+			start = Some(i);
+		}
+	}
+	if let Some(start) = start {
+		fields.push(&value[start..]);
+	}
+
+	if fields.len() < 2 {
+		return None;
+	}
+
+	Some(Geolocation {
+		latitude: parse_coordinate(fields[0], 2)?,
+		longitude: parse_coordinate(fields[1], 3)?,
+		altitude: fields.get(2).and_then(|field| field.parse().ok()),
+	})
+}
+
+/// Parses one signed ISO 6709 coordinate field into decimal degrees.
+/// `degree_digits` is the fixed width of the degrees part for this field's
+/// role - 2 for latitude (`DD`), 3 for longitude (`DDD`) - which is what lets
+/// us tell the plain-degree, degree-minute and degree-minute-second forms
+/// apart: each adds exactly two more integer digits than the last.
+fn parse_coordinate(field: &str, degree_digits: usize) -> Option<f64> {
+	let (sign, rest) = match field.as_bytes().first()? {
+		b'+' => (1.0, &field[1..]),
+		b'-' => (-1.0, &field[1..]),
+		_ => return None,
+	};
+
+	// Every byte offset below assumes single-byte ASCII digits, so a stray
+	// multi-byte character (corrupted/malicious tag) must bail out here
+	// rather than risk slicing on a non-char-boundary byte index and panicking.
+	if !rest.is_ascii() {
+		return None;
+	}
+
+	let int_len = rest.find('.').unwrap_or(rest.len());
+
+	let magnitude = if int_len == degree_digits {
+		// Plain decimal degrees, e.g. `40.7589`.
+		rest.parse::<f64>().ok()?
+	} else if int_len == degree_digits + 2 {
+		// Degrees + decimal minutes, e.g. `4045.6` -> 40 + 45.6/60.
+		let degrees: f64 = rest[..degree_digits].parse().ok()?;
+		let minutes: f64 = rest[degree_digits..].parse().ok()?;
+		degrees + minutes / 60.0
+	} else if int_len == degree_digits + 4 {
+		// Degrees + minutes + decimal seconds, e.g. `404530.5` -> 40 + 45/60 + 30.5/3600.
+		let degrees: f64 = rest[..degree_digits].parse().ok()?;
+		let minutes: f64 = rest[degree_digits..degree_digits + 2].parse().ok()?;
+		let seconds: f64 = rest[degree_digits + 2..].parse().ok()?;
+		degrees + minutes / 60.0 + seconds / 3600.0
+	} else {
+		return None;
+	};
+
+	Some(sign * magnitude)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn plain_degrees() {
+		let location = parse_iso6709("+40.7589-073.9851+010.273/").unwrap();
+		assert_eq!(location.latitude, 40.7589);
+		assert_eq!(location.longitude, -73.9851);
+		assert_eq!(location.altitude, Some(10.273));
+	}
+
+	#[test]
+	fn missing_altitude() {
+		let location = parse_iso6709("+40.7589-073.9851/").unwrap();
+		assert_eq!(location.latitude, 40.7589);
+		assert_eq!(location.longitude, -73.9851);
+		assert_eq!(location.altitude, None);
+	}
+
+	#[test]
+	fn degree_minute() {
+		let location = parse_iso6709("+4045.6-07359.1/").unwrap();
+		assert!((location.latitude - (40.0 + 45.6 / 60.0)).abs() < 1e-9);
+		assert!((location.longitude - -(73.0 + 59.1 / 60.0)).abs() < 1e-9);
+	}
+
+	#[test]
+	fn degree_minute_second() {
+		let location = parse_iso6709("+404530.5-0735930.2/").unwrap();
+		assert!((location.latitude - (40.0 + 45.0 / 60.0 + 30.5 / 3600.0)).abs() < 1e-9);
+		assert!((location.longitude - -(73.0 + 59.0 / 60.0 + 30.2 / 3600.0)).abs() < 1e-9);
+	}
+
+	#[test]
+	fn malformed_falls_back_to_none() {
+		assert_eq!(parse_iso6709("not a coordinate"), None);
+		assert_eq!(parse_iso6709("+40.7589/"), None);
+	}
+
+	#[test]
+	fn non_ascii_does_not_panic() {
+		assert_eq!(parse_iso6709("+1é234.5-073.9851/"), None);
+	}
+}
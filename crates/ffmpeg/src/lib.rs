@@ -0,0 +1,11 @@
+//! Thin wrapper around `ffmpeg-sys-next` for probing media files: reads a
+//! container's textual tags and embedded cover art into [`MediaMetadata`].
+
+pub mod cover_art;
+pub(crate) mod dict;
+pub mod geolocation;
+pub mod model;
+
+pub use cover_art::EmbeddedImage;
+pub use geolocation::Geolocation;
+pub use model::MediaMetadata;
@@ -0,0 +1,42 @@
+//! The metadata a probing pass extracts from a media container - textual tags
+//! read off its `FFmpegDict`, plus structured fields (geolocation, embedded
+//! cover art) parsed out of specific well-known tags or streams.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::{cover_art::EmbeddedImage, geolocation::Geolocation};
+
+#[derive(Debug, Clone, Default)]
+pub struct MediaMetadata {
+	pub album: Option<String>,
+	pub album_artist: Option<String>,
+	pub artist: Option<String>,
+	pub comment: Option<String>,
+	pub composer: Option<String>,
+	pub copyright: Option<String>,
+	pub creation_time: Option<DateTime<Utc>>,
+	pub date: Option<DateTime<Utc>>,
+	pub disc: Option<u32>,
+	pub encoder: Option<String>,
+	pub encoded_by: Option<String>,
+	pub filename: Option<String>,
+	pub genre: Option<String>,
+	pub language: Option<String>,
+	pub location: Option<Geolocation>,
+	pub performer: Option<String>,
+	pub publisher: Option<String>,
+	pub service_name: Option<String>,
+	pub service_provider: Option<String>,
+	pub title: Option<String>,
+	pub track: Option<u32>,
+	pub variant_bitrate: Option<u64>,
+	/// Tags that didn't map to a typed field above, plus the raw `location`
+	/// tag value when it couldn't be parsed as ISO 6709. Other typed fields
+	/// whose value fails to parse are left unset and not kept here.
+	pub custom: HashMap<String, String>,
+	/// The container's embedded cover art / attached picture, if any, as
+	/// extracted by [`crate::cover_art::extract_embedded_artwork`].
+	pub embedded_artwork: Option<EmbeddedImage>,
+}